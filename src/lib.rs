@@ -37,6 +37,11 @@
 #![deny(clippy::suspicious)]
 #![warn(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(
+    feature = "coerce_unsized",
+    feature(coerce_unsized, unsize)
+)]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
 
 #[cfg(not(feature = "std"))]
 extern crate alloc;
@@ -74,6 +74,19 @@ use std::sync::{Arc, Weak};
 /// ```
 pub struct Wobbly<T: ?Sized> {
     weak: Weak<T>,
+    // The shared `should_decref` group flag lives in its own allocation, so
+    // each `Wobbly::new` performs one heap allocation on top of the value's.
+    //
+    // WON'T-DO (chunk0-5): the requested single-allocation redesign — packing
+    // the flag alongside the value in one `Arc<Inner<T>>` the way `Arc` packs
+    // its counts into an `ArcInner` — cannot preserve the public API. The
+    // packed inner would make the backing pointer an `Arc<Inner<T>>`, yet
+    // `upgrade` must keep returning `Arc<T>`, and std `Arc` exposes no way to
+    // project a shared `Arc<Inner<T>>` down to an `Arc<T>` over the same
+    // allocation. The other direction is blocked too: `new` consumes an
+    // already laid-out `Arc<T>` (possibly `?Sized`, possibly shared by other
+    // `Arc`s, with a pointee address observable through `as_ptr`/`ptr_eq`), so
+    // it cannot repack the value into a bespoke inner without reallocating it.
     should_decref: Arc<AtomicBool>,
 }
 
@@ -99,6 +112,103 @@ impl<T: ?Sized> Wobbly<T> {
         }
     }
 
+    /// Creates a new `Wobbly<T>` from an owning (strong) [`Arc`] pointer,
+    /// returning an error instead of aborting if the group flag allocation
+    /// fails.
+    ///
+    /// Like [`new`], this consumes the owning (strong) pointer, but the extra
+    /// allocation needed to track the group is performed fallibly. If it fails,
+    /// the original [`Arc`] is handed back unchanged alongside the
+    /// [`AllocError`](core::alloc::AllocError) so the caller can recover.
+    ///
+    /// # Errors
+    ///
+    /// Returns the unmodified owning (strong) [`Arc`] together with an
+    /// [`AllocError`](core::alloc::AllocError) if the group flag allocation
+    /// fails.
+    ///
+    /// [`new`]: Wobbly::new
+    #[cfg(feature = "allocator_api")]
+    pub fn try_new(strong: Arc<T>) -> Result<Self, (Arc<T>, core::alloc::AllocError)> {
+        let should_decref = match Arc::try_new(AtomicBool::new(true)) {
+            Ok(should_decref) => should_decref,
+            Err(err) => return Err((strong, err)),
+        };
+
+        let weak = Arc::downgrade(&strong);
+
+        // leak one strong reference count
+        core::mem::forget(strong);
+
+        Ok(Self {
+            weak,
+            should_decref,
+        })
+    }
+
+    /// Creates a new `Wobbly<T>` in a two-step process that allows the inner
+    /// value to embed [`clone`][clone]s of the very `Wobbly` pointer to itself.
+    ///
+    /// `data_fn` is handed a `&Wobbly<T>` to this allocation before the inner
+    /// value is fully constructed, mirroring [`Arc::new_cyclic`] but passing a
+    /// `Wobbly` rather than a [`Weak`]. Any clones stored in the returned value
+    /// join the same group, so the value keeps itself alive until the first of
+    /// its `Wobbly`s is dropped, at which point the owning (strong) pointer is
+    /// released and the cycle is broken.
+    ///
+    /// ```
+    /// # use wobbly::sync::Wobbly;
+    /// struct Node {
+    ///     me: Wobbly<Node>,
+    /// }
+    ///
+    /// let node = Wobbly::new_cyclic(|me| Node { me: me.clone() });
+    ///
+    /// // the self-reference keeps the value alive while the group is intact
+    /// let strong = node.upgrade().expect("kept alive by its own group");
+    /// assert!(strong.me.ptr_eq(&node));
+    /// drop(strong);
+    ///
+    /// // dropping the first `Wobbly` of the group breaks the cycle and frees
+    /// // the value; the stored clone inside `Node` must not decrement again
+    /// drop(node);
+    /// ```
+    ///
+    /// [clone]: Clone::clone
+    #[must_use]
+    pub fn new_cyclic<F>(data_fn: F) -> Self
+    where
+        F: FnOnce(&Self) -> T,
+        T: Sized,
+    {
+        // allocate the group flag with decrementing disabled for now, since
+        // the allocation's strong count is still 0 and its value is not yet
+        // initialised during construction, so a premature decrement would be
+        // undefined behaviour
+        let should_decref = Arc::new(AtomicBool::new(false));
+
+        let strong = Arc::new_cyclic(|weak| {
+            let wobbly = Self {
+                weak: weak.clone(),
+                should_decref: should_decref.clone(),
+            };
+            data_fn(&wobbly)
+        });
+
+        let weak = Arc::downgrade(&strong);
+
+        // leak one strong reference count, just like `new`
+        core::mem::forget(strong);
+
+        // the leaked strong is now the group's to release
+        should_decref.store(true, Ordering::Relaxed);
+
+        Self {
+            weak,
+            should_decref,
+        }
+    }
+
     /// Creates a new [`Weak`] pointer to this allocation.
     #[must_use]
     #[inline]
@@ -106,6 +216,91 @@ impl<T: ?Sized> Wobbly<T> {
         self.weak.clone()
     }
 
+    /// Returns a raw pointer to the value that this `Wobbly` points to.
+    ///
+    /// The pointer is valid only if there are some strong references alive; it
+    /// may be dangling, unaligned, or even [`null`](core::ptr::null) otherwise.
+    #[must_use]
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self.weak.as_ptr()
+    }
+
+    /// Returns `true` if `self` and `other` point to the same allocation, in a
+    /// vein similar to [`Arc::ptr_eq`].
+    #[must_use]
+    #[inline]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self.weak.as_ptr().cast::<()>(), other.weak.as_ptr().cast::<()>())
+    }
+
+    /// Returns `true` if `self` and the [`Weak`] `other` point to the same
+    /// allocation, in a vein similar to [`Arc::ptr_eq`].
+    #[must_use]
+    #[inline]
+    pub fn ptr_eq_weak(&self, other: &Weak<T>) -> bool {
+        core::ptr::eq(self.weak.as_ptr().cast::<()>(), other.as_ptr().cast::<()>())
+    }
+
+    /// Consumes the `Wobbly`, returning the two raw pointers that make up its
+    /// state without changing any reference counts.
+    ///
+    /// A `Wobbly` owns both a [`Weak`] to the value and the shared group flag,
+    /// so both are handed back in a [`WobblyRaw`]. To avoid a leak, the raw
+    /// pair must later be turned back into a `Wobbly` using [`from_raw`].
+    ///
+    /// ```
+    /// # use wobbly::sync::Wobbly;
+    /// use std::sync::Arc;
+    ///
+    /// let wobbly = Wobbly::new(Arc::new(7_i32));
+    /// assert_eq!(wobbly.strong_count(), 1);
+    /// assert_eq!(wobbly.weak_count(), 1);
+    ///
+    /// // a round-trip through the raw pair changes no reference counts
+    /// let raw = wobbly.into_raw();
+    /// let wobbly = unsafe { Wobbly::from_raw(raw) };
+    /// assert_eq!(wobbly.strong_count(), 1);
+    /// assert_eq!(wobbly.weak_count(), 1);
+    /// assert_eq!(*wobbly.upgrade().unwrap(), 7);
+    /// ```
+    ///
+    /// [`from_raw`]: Wobbly::from_raw
+    #[must_use]
+    pub fn into_raw(self) -> WobblyRaw<T> {
+        let this = core::mem::ManuallyDrop::new(self);
+
+        // Safety:
+        // - `this` is never dropped, so we may move its fields out by reading
+        let weak = unsafe { core::ptr::read(core::ptr::addr_of!(this.weak)) };
+        let should_decref = unsafe { core::ptr::read(core::ptr::addr_of!(this.should_decref)) };
+
+        WobblyRaw {
+            data: Weak::into_raw(weak),
+            should_decref: Arc::into_raw(should_decref),
+        }
+    }
+
+    /// Reconstructs a `Wobbly` from the raw pointers returned by [`into_raw`],
+    /// without changing any reference counts.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must have been produced by a call to [`into_raw`], and each such
+    /// raw pair must be consumed by `from_raw` exactly once. Reconstructing the
+    /// same pair more than once, or passing pointers from different `Wobbly`s,
+    /// is undefined behaviour.
+    ///
+    /// [`into_raw`]: Wobbly::into_raw
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)] // the consume-exactly-once contract requires ownership
+    pub unsafe fn from_raw(raw: WobblyRaw<T>) -> Self {
+        Self {
+            weak: Weak::from_raw(raw.data),
+            should_decref: Arc::from_raw(raw.should_decref),
+        }
+    }
+
     /// Attempts to upgrade the `Wobbly` pointer to an [`Arc`], delaying
     /// dropping of the inner value if successful.
     ///
@@ -139,6 +334,22 @@ impl<T: ?Sized> Wobbly<T> {
     }
 }
 
+#[cfg(feature = "coerce_unsized")]
+impl<T: ?Sized + core::marker::Unsize<U>, U: ?Sized> core::ops::CoerceUnsized<Wobbly<U>>
+    for Wobbly<T>
+{
+}
+
+/// The raw pointers that make up a [`Wobbly`], as returned by
+/// [`Wobbly::into_raw`] and accepted by [`Wobbly::from_raw`].
+///
+/// It carries both the raw [`Weak`] to the value and the raw shared group flag
+/// that tracks whether the owning (strong) pointer has already been released.
+pub struct WobblyRaw<T: ?Sized> {
+    data: *const T,
+    should_decref: *const AtomicBool,
+}
+
 impl<T: ?Sized> Clone for Wobbly<T> {
     /// Makes a clone of the `Wobbly` pointer that points to the same allocation.
     ///